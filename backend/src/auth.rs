@@ -0,0 +1,164 @@
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::{
+    extract::{FromRequestParts, State},
+    http::{header, request::Parts, StatusCode},
+    Json,
+};
+use axum_extra::{headers::Cookie, TypedHeader};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::error::AppError;
+use crate::models::{LoginUser, RegisterUser, User};
+use crate::schema::ApiResponse;
+
+const COOKIE_NAME: &str = "token";
+
+type AppState = Arc<crate::AppState>;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: i64,
+    iat: i64,
+    exp: i64,
+}
+
+fn hash_password(password: &str) -> Result<String, AppError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|_| AppError::InternalError)
+}
+
+fn verify_password(password: &str, hash: &str) -> Result<(), AppError> {
+    let parsed_hash = PasswordHash::new(hash).map_err(|_| AppError::InternalError)?;
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .map_err(|_| AppError::Unauthorized)
+}
+
+fn sign_jwt(user_id: i64, state: &AppState) -> Result<String, AppError> {
+    let now = chrono::Utc::now().timestamp();
+    let claims = Claims {
+        sub: user_id,
+        iat: now,
+        exp: now + state.config.jwt_maxage,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+    )
+    .map_err(|_| AppError::InternalError)
+}
+
+/// Extracts and validates the signed-in user from the `token` cookie.
+pub struct AuthUser(pub User);
+
+impl<S> FromRequestParts<S> for AuthUser
+where
+    AppState: FromRequestParts<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let state = AppState::from_request_parts(parts, state)
+            .await
+            .map_err(|_| AppError::Unauthorized)?;
+
+        let TypedHeader(cookies) = TypedHeader::<Cookie>::from_request_parts(parts, &state)
+            .await
+            .map_err(|_| AppError::Unauthorized)?;
+
+        let token = cookies.get(COOKIE_NAME).ok_or(AppError::Unauthorized)?;
+
+        let claims = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| AppError::Unauthorized)?
+        .claims;
+
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, username, password_hash, created_at FROM users WHERE id = ?",
+        )
+        .bind(claims.sub)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(AppError::DatabaseError)?
+        .ok_or(AppError::Unauthorized)?;
+
+        Ok(AuthUser(user))
+    }
+}
+
+pub async fn register(
+    State(state): State<AppState>,
+    Json(payload): Json<RegisterUser>,
+) -> Result<ApiResponse<User>, AppError> {
+    if payload.username.len() < 3 {
+        return Err(AppError::ValidationError(
+            "Username must be at least 3 characters".to_string(),
+        ));
+    }
+
+    if payload.password.len() < 8 {
+        return Err(AppError::ValidationError(
+            "Password must be at least 8 characters".to_string(),
+        ));
+    }
+
+    let password_hash = hash_password(&payload.password)?;
+
+    let user = sqlx::query_as::<_, User>(
+        r#"
+        INSERT INTO users (username, password_hash)
+        VALUES (?, ?)
+        RETURNING id, username, password_hash, created_at
+        "#,
+    )
+    .bind(&payload.username)
+    .bind(&password_hash)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+            AppError::ValidationError("Username already taken".to_string())
+        }
+        _ => AppError::DatabaseError(e),
+    })?;
+
+    Ok(ApiResponse::new(user, StatusCode::CREATED))
+}
+
+pub async fn login(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginUser>,
+) -> Result<([(header::HeaderName, String); 1], ApiResponse<User>), AppError> {
+    let user = sqlx::query_as::<_, User>(
+        "SELECT id, username, password_hash, created_at FROM users WHERE username = ?",
+    )
+    .bind(&payload.username)
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::Unauthorized)?;
+
+    verify_password(&payload.password, &user.password_hash)?;
+
+    let token = sign_jwt(user.id, &state)?;
+    let cookie = format!(
+        "{COOKIE_NAME}={token}; Max-Age={}; Path=/; HttpOnly; SameSite=Strict",
+        state.config.jwt_maxage
+    );
+
+    Ok((
+        [(header::SET_COOKIE, cookie)],
+        ApiResponse::new(user, StatusCode::OK),
+    ))
+}
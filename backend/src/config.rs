@@ -0,0 +1,107 @@
+use serde::Deserialize;
+
+const DEFAULT_DATABASE_URL: &str = "sqlite:///app/data/blog.db";
+const DEFAULT_BIND_ADDR: &str = "0.0.0.0";
+const DEFAULT_PORT: u16 = 3001;
+const DEFAULT_MAX_CONNECTIONS: u32 = 5;
+const DEFAULT_JWT_MAXAGE_SECONDS: i64 = 60 * 60 * 24;
+const DEFAULT_CORS_ORIGINS: &[&str] = &["http://localhost:3000", "http://frontend:3000"];
+
+/// Optional `config.toml` overrides, read before environment variables are
+/// applied. Every field is optional so a deployment can override as little or
+/// as much as it needs.
+#[derive(Debug, Deserialize, Default)]
+struct FileConfig {
+    database_url: Option<String>,
+    bind_addr: Option<String>,
+    port: Option<u16>,
+    max_connections: Option<u32>,
+    jwt_maxage_seconds: Option<i64>,
+    cors_origins: Option<Vec<String>>,
+}
+
+fn load_file_config() -> FileConfig {
+    std::fs::read_to_string("config.toml")
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Typed application configuration, assembled once at startup from
+/// `config.toml` (if present) and environment variables, with environment
+/// variables taking precedence. Secrets have no default and `load()` panics
+/// if they're missing, so misconfiguration fails fast instead of at request
+/// time.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub bind_addr: String,
+    pub port: u16,
+    pub max_connections: u32,
+    pub jwt_secret: String,
+    pub jwt_maxage: i64,
+    pub cors_origins: Vec<String>,
+}
+
+impl Config {
+    pub fn load() -> Self {
+        let file = load_file_config();
+
+        let database_url = std::env::var("DATABASE_URL")
+            .ok()
+            .or(file.database_url)
+            .unwrap_or_else(|| DEFAULT_DATABASE_URL.to_string());
+
+        let bind_addr = std::env::var("BIND_ADDR")
+            .ok()
+            .or(file.bind_addr)
+            .unwrap_or_else(|| DEFAULT_BIND_ADDR.to_string());
+
+        let port = std::env::var("PORT")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .or(file.port)
+            .unwrap_or(DEFAULT_PORT);
+
+        let max_connections = std::env::var("MAX_CONNECTIONS")
+            .ok()
+            .and_then(|m| m.parse().ok())
+            .or(file.max_connections)
+            .unwrap_or(DEFAULT_MAX_CONNECTIONS);
+
+        let jwt_secret =
+            std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+
+        let jwt_maxage = std::env::var("JWT_MAXAGE_SECONDS")
+            .ok()
+            .and_then(|m| m.parse().ok())
+            .or(file.jwt_maxage_seconds)
+            .unwrap_or(DEFAULT_JWT_MAXAGE_SECONDS);
+
+        let cors_origins = std::env::var("CORS_ORIGINS")
+            .ok()
+            .map(|origins| {
+                origins
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .or(file.cors_origins)
+            .unwrap_or_else(|| DEFAULT_CORS_ORIGINS.iter().map(|s| s.to_string()).collect());
+
+        Config {
+            database_url,
+            bind_addr,
+            port,
+            max_connections,
+            jwt_secret,
+            jwt_maxage,
+            cors_origins,
+        }
+    }
+
+    pub fn bind_address(&self) -> String {
+        format!("{}:{}", self.bind_addr, self.port)
+    }
+}
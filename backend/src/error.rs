@@ -3,8 +3,21 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use serde::Serialize;
 use serde_json::json;
 use thiserror::Error;
+use utoipa::ToSchema;
+
+use crate::request_id::current_request_id;
+
+/// Shape of the JSON body returned by [`AppError::into_response`], documented
+/// for OpenAPI consumers.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub error: String,
+    pub status: u16,
+    pub request_id: String,
+}
 
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -17,6 +30,12 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Forbidden")]
+    Forbidden,
+
     #[error("Internal server error")]
     InternalError,
 }
@@ -36,6 +55,14 @@ impl IntoResponse for AppError {
                 StatusCode::BAD_REQUEST,
                 msg
             ),
+            AppError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized".to_string()
+            ),
+            AppError::Forbidden => (
+                StatusCode::FORBIDDEN,
+                "Forbidden".to_string()
+            ),
             AppError::InternalError => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Internal server error".to_string()
@@ -45,6 +72,7 @@ impl IntoResponse for AppError {
         let body = Json(json!({
             "error": error_message,
             "status": status.as_u16(),
+            "request_id": current_request_id(),
         }));
 
         (status, body).into_response()
@@ -3,53 +3,205 @@ use axum::{
     http::StatusCode,
     Json,
 };
-use sqlx::SqlitePool;
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
 use std::sync::Arc;
 
-use crate::error::AppError;
+use crate::auth::AuthUser;
+use crate::error::{AppError, ErrorBody};
+use crate::ids;
 use crate::models::{Post, CreatePost, UpdatePost};
-use crate::schema::{PaginationParams, PaginatedResponse};
+use crate::schema::{
+    ApiResponse, ApiResponsePaginatedResponsePost, ApiResponsePost, PaginatedResponse,
+    PaginationParams,
+};
 
 type AppState = Arc<crate::AppState>;
 
+const ALLOWED_SORT_COLUMNS: &[&str] = &["created_at", "updated_at"];
+const ALLOWED_SORT_ORDERS: &[&str] = &["asc", "desc"];
+/// Sorts by FTS5 relevance (`bm25`); only meaningful alongside a `q`, so it's
+/// accepted separately from `ALLOWED_SORT_COLUMNS` rather than folded in.
+const RANK_SORT: &str = "rank";
+
+/// Pushes the `WHERE` predicates shared by the listing query and its count
+/// query, so the total always reflects the same filters as the page of data.
+fn push_filters<'a>(builder: &mut QueryBuilder<'a, Sqlite>, params: &'a PaginationParams) {
+    let mut has_filter = false;
+
+    if let Some(q) = params.q.as_deref().filter(|q| !q.is_empty()) {
+        builder.push(" WHERE posts_fts MATCH ");
+        builder.push_bind(q);
+        has_filter = true;
+    }
+
+    if let Some(status) = params.status.as_deref().filter(|s| !s.is_empty()) {
+        builder.push(if has_filter { " AND " } else { " WHERE " });
+        builder.push("posts.status = ");
+        builder.push_bind(status);
+        has_filter = true;
+    }
+
+    if let Some(author) = params.author.as_deref().filter(|a| !a.is_empty()) {
+        builder.push(if has_filter { " AND " } else { " WHERE " });
+        builder.push("posts.author = ");
+        builder.push_bind(author);
+    }
+}
+
+/// Maps a query failure to a 400 when it's a malformed FTS5 `MATCH`
+/// expression (unbalanced quotes, a bare `NOT`/`AND`, etc.) rather than a
+/// genuine database error, since that's caller error, not ours. SQLite
+/// reports these as a generic `SQLITE_ERROR` tagged with an `fts5:` prefix in
+/// the message, so only that case is downgraded — a locked database, I/O
+/// error, etc. that happens to occur on a search request still surfaces as
+/// `DatabaseError`.
+fn map_fts_error(e: sqlx::Error, searching: bool) -> AppError {
+    if searching {
+        if let sqlx::Error::Database(ref db_err) = e {
+            if db_err.message().to_ascii_lowercase().contains("fts5") {
+                return AppError::ValidationError(format!("Invalid search query: {}", db_err.message()));
+            }
+        }
+    }
+
+    AppError::DatabaseError(e)
+}
+
+/// Loads a post's current author and checks it against `username`, so
+/// mutating routes can reject edits from anyone but the post's owner.
+pub(crate) async fn require_owner(db: &SqlitePool, id: i64, username: &str) -> Result<(), AppError> {
+    let (author,): (String,) = sqlx::query_as("SELECT author FROM posts WHERE id = ?")
+        .bind(id)
+        .fetch_one(db)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => AppError::NotFound,
+            _ => AppError::DatabaseError(e),
+        })?;
+
+    if author != username {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated list of posts", body = ApiResponsePaginatedResponsePost),
+        (status = 400, description = "Unknown sort key, or malformed search query", body = ErrorBody),
+        (status = 500, description = "Database error", body = ErrorBody),
+    ),
+)]
 pub async fn list_posts(
     State(state): State<AppState>,
     Query(params): Query<PaginationParams>,
-) -> Result<Json<PaginatedResponse<Post>>, AppError> {
+) -> Result<ApiResponse<PaginatedResponse<Post>>, AppError> {
+    let searching = params.q.as_deref().is_some_and(|q| !q.is_empty());
+
+    if params.sort != RANK_SORT && !ALLOWED_SORT_COLUMNS.contains(&params.sort.as_str()) {
+        return Err(AppError::ValidationError(format!(
+            "Unknown sort key: {}",
+            params.sort
+        )));
+    }
+
+    if params.sort == RANK_SORT && !searching {
+        return Err(AppError::ValidationError(
+            "Sort key 'rank' requires a search query".to_string(),
+        ));
+    }
+
+    if !ALLOWED_SORT_ORDERS.contains(&params.order.as_str()) {
+        return Err(AppError::ValidationError(format!(
+            "Unknown sort order: {}",
+            params.order
+        )));
+    }
+
     let page = params.page.max(1);
     let per_page = params.per_page.clamp(1, 100);
     let offset = (page - 1) * per_page;
 
-    let posts = sqlx::query_as::<_, Post>(
-        "SELECT id, title, content, author, status, created_at, updated_at 
-         FROM posts 
-         ORDER BY created_at DESC 
-         LIMIT ? OFFSET ?"
-    )
-    .bind(per_page)
-    .bind(offset)
-    .fetch_all(&state.db)
-    .await?;
+    let mut query = QueryBuilder::new(
+        "SELECT posts.id, posts.title, posts.content, posts.author, posts.status, \
+         posts.image_path, posts.thumb_path, posts.created_at, posts.updated_at \
+         FROM posts"
+    );
+    if searching {
+        query.push(" JOIN posts_fts ON posts_fts.rowid = posts.id");
+    }
+    push_filters(&mut query, &params);
+    // bm25() is lower-is-better, the opposite of the plain columns above, so
+    // `rank` always sorts ascending regardless of `order` — the natural
+    // reading of "sort=rank" is "most relevant first", and `order` has no
+    // sensible meaning against a relevance score a caller never sees.
+    let (order_column, order_direction) = if params.sort == RANK_SORT {
+        ("bm25(posts_fts)".to_string(), "ASC")
+    } else {
+        (format!("posts.{}", params.sort), params.order.as_str())
+    };
+    query.push(format!(" ORDER BY {} {}", order_column, order_direction));
+    query.push(" LIMIT ");
+    query.push_bind(per_page);
+    query.push(" OFFSET ");
+    query.push_bind(offset);
+
+    let mut posts = query
+        .build_query_as::<Post>()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| map_fts_error(e, searching))?;
+
+    for post in &mut posts {
+        post.sqid = ids::encode(&state.sqids, post.id)?;
+    }
+
+    let mut count_query = QueryBuilder::new("SELECT COUNT(*) FROM posts");
+    if searching {
+        count_query.push(" JOIN posts_fts ON posts_fts.rowid = posts.id");
+    }
+    push_filters(&mut count_query, &params);
 
-    let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM posts")
+    let (total,): (i64,) = count_query
+        .build_query_as()
         .fetch_one(&state.db)
-        .await?;
+        .await
+        .map_err(|e| map_fts_error(e, searching))?;
 
-    Ok(Json(PaginatedResponse {
-        data: posts,
-        page,
-        per_page,
-        total,
-    }))
+    Ok(ApiResponse::new(
+        PaginatedResponse {
+            data: posts,
+            page,
+            per_page,
+            total,
+        },
+        StatusCode::OK,
+    ))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/posts/{sqid}",
+    params(("sqid" = String, Path, description = "Opaque post id")),
+    responses(
+        (status = 200, description = "The requested post", body = ApiResponsePost),
+        (status = 404, description = "Post not found", body = ErrorBody),
+        (status = 500, description = "Database error", body = ErrorBody),
+    ),
+)]
 pub async fn get_post(
     State(state): State<AppState>,
-    Path(id): Path<i64>,
-) -> Result<Json<Post>, AppError> {
-    let post = sqlx::query_as::<_, Post>(
-        "SELECT id, title, content, author, status, created_at, updated_at 
-         FROM posts 
+    Path(sqid): Path<String>,
+) -> Result<ApiResponse<Post>, AppError> {
+    let id = ids::decode(&state.sqids, &sqid).ok_or(AppError::NotFound)?;
+
+    let mut post = sqlx::query_as::<_, Post>(
+        "SELECT id, title, content, author, status, image_path, thumb_path, created_at, updated_at
+         FROM posts
          WHERE id = ?"
     )
     .bind(id)
@@ -60,13 +212,27 @@ pub async fn get_post(
         _ => AppError::DatabaseError(e),
     })?;
 
-    Ok(Json(post))
+    post.sqid = sqid;
+
+    Ok(ApiResponse::new(post, StatusCode::OK))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/posts",
+    request_body = CreatePost,
+    responses(
+        (status = 201, description = "Post created", body = ApiResponsePost),
+        (status = 400, description = "Validation error", body = ErrorBody),
+        (status = 401, description = "Missing or invalid credentials", body = ErrorBody),
+        (status = 500, description = "Database error", body = ErrorBody),
+    ),
+)]
 pub async fn create_post(
     State(state): State<AppState>,
+    AuthUser(user): AuthUser,
     Json(payload): Json<CreatePost>,
-) -> Result<(StatusCode, Json<Post>), AppError> {
+) -> Result<ApiResponse<Post>, AppError> {
     if payload.title.len() < 3 {
         return Err(AppError::ValidationError(
             "Title must be at least 3 characters".to_string()
@@ -79,34 +245,45 @@ pub async fn create_post(
         ));
     }
 
-    if payload.author.is_empty() {
-        return Err(AppError::ValidationError(
-            "Author cannot be empty".to_string()
-        ));
-    }
-
-    let post = sqlx::query_as::<_, Post>(
+    let mut post = sqlx::query_as::<_, Post>(
         r#"
         INSERT INTO posts (title, content, author, status)
         VALUES (?, ?, ?, ?)
-        RETURNING id, title, content, author, status, created_at, updated_at
+        RETURNING id, title, content, author, status, image_path, thumb_path, created_at, updated_at
         "#
     )
     .bind(&payload.title)
     .bind(&payload.content)
-    .bind(&payload.author)
+    .bind(&user.username)
     .bind(&payload.status)
     .fetch_one(&state.db)
     .await?;
 
-    Ok((StatusCode::CREATED, Json(post)))
+    post.sqid = ids::encode(&state.sqids, post.id)?;
+
+    Ok(ApiResponse::new(post, StatusCode::CREATED))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/posts/{sqid}",
+    params(("sqid" = String, Path, description = "Opaque post id")),
+    request_body = UpdatePost,
+    responses(
+        (status = 200, description = "Post updated", body = ApiResponsePost),
+        (status = 400, description = "Validation error", body = ErrorBody),
+        (status = 401, description = "Missing or invalid credentials", body = ErrorBody),
+        (status = 403, description = "Not the post's author", body = ErrorBody),
+        (status = 404, description = "Post not found", body = ErrorBody),
+        (status = 500, description = "Database error", body = ErrorBody),
+    ),
+)]
 pub async fn update_post(
     State(state): State<AppState>,
-    Path(id): Path<i64>,
+    AuthUser(user): AuthUser,
+    Path(sqid): Path<String>,
     Json(payload): Json<UpdatePost>,
-) -> Result<Json<Post>, AppError> {
+) -> Result<ApiResponse<Post>, AppError> {
     if let Some(ref title) = payload.title {
         if title.len() < 3 {
             return Err(AppError::ValidationError(
@@ -115,21 +292,22 @@ pub async fn update_post(
         }
     }
 
-    let post = sqlx::query_as::<_, Post>(
+    let id = ids::decode(&state.sqids, &sqid).ok_or(AppError::NotFound)?;
+    require_owner(&state.db, id, &user.username).await?;
+
+    let mut post = sqlx::query_as::<_, Post>(
         r#"
-        UPDATE posts 
+        UPDATE posts
         SET title = COALESCE(?, title),
             content = COALESCE(?, content),
-            author = COALESCE(?, author),
             status = COALESCE(?, status),
             updated_at = CURRENT_TIMESTAMP
         WHERE id = ?
-        RETURNING id, title, content, author, status, created_at, updated_at
+        RETURNING id, title, content, author, status, image_path, thumb_path, created_at, updated_at
         "#
     )
     .bind(payload.title)
     .bind(payload.content)
-    .bind(payload.author)
     .bind(payload.status)
     .bind(id)
     .fetch_one(&state.db)
@@ -139,13 +317,31 @@ pub async fn update_post(
         _ => AppError::DatabaseError(e),
     })?;
 
-    Ok(Json(post))
+    post.sqid = sqid;
+
+    Ok(ApiResponse::new(post, StatusCode::OK))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/posts/{sqid}",
+    params(("sqid" = String, Path, description = "Opaque post id")),
+    responses(
+        (status = 204, description = "Post deleted"),
+        (status = 401, description = "Missing or invalid credentials", body = ErrorBody),
+        (status = 403, description = "Not the post's author", body = ErrorBody),
+        (status = 404, description = "Post not found", body = ErrorBody),
+        (status = 500, description = "Database error", body = ErrorBody),
+    ),
+)]
 pub async fn delete_post(
     State(state): State<AppState>,
-    Path(id): Path<i64>,
+    AuthUser(user): AuthUser,
+    Path(sqid): Path<String>,
 ) -> Result<StatusCode, AppError> {
+    let id = ids::decode(&state.sqids, &sqid).ok_or(AppError::NotFound)?;
+    require_owner(&state.db, id, &user.username).await?;
+
     let result = sqlx::query("DELETE FROM posts WHERE id = ?")
         .bind(id)
         .execute(&state.db)
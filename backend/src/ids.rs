@@ -0,0 +1,38 @@
+use sqids::Sqids;
+
+use crate::error::AppError;
+
+const DEFAULT_ALPHABET: &str =
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Builds the encoder used to turn internal post ids into opaque, non-sequential
+/// public ids. The alphabet is configurable so a deployment can keep its own
+/// sqids unguessable even if this source is public.
+pub fn build_sqids() -> Sqids {
+    let alphabet = std::env::var("SQIDS_ALPHABET").unwrap_or_else(|_| DEFAULT_ALPHABET.to_string());
+
+    Sqids::builder()
+        .alphabet(alphabet.chars().collect())
+        .min_length(6)
+        .build()
+        .expect("SQIDS_ALPHABET must be a valid sqids alphabet")
+}
+
+/// Encodes an internal post id as its public sqid. Returns
+/// [`AppError::InternalError`] instead of panicking if sqids can't produce an
+/// id within its attempt budget, since this runs on every successful
+/// list/get/create/update/upload response, not just at startup.
+pub fn encode(sqids: &Sqids, id: i64) -> Result<String, AppError> {
+    sqids
+        .encode(&[id as u64])
+        .map_err(|_| AppError::InternalError)
+}
+
+/// Decodes a public sqid back to the internal row id, returning `None` for
+/// anything that doesn't round-trip to a single id.
+pub fn decode(sqids: &Sqids, value: &str) -> Option<i64> {
+    match sqids.decode(value).as_slice() {
+        [id] => Some(*id as i64),
+        _ => None,
+    }
+}
@@ -1,18 +1,38 @@
 use axum::{
-    extract::{Path, State},
+    extract::DefaultBodyLimit,
     http::{HeaderValue, Method, StatusCode},
+    middleware,
     routing::{get, post},
-    Json, Router,
+    Router,
 };
-use serde::{Deserialize, Serialize};
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use std::sync::Arc;
 use std::time::Duration;
 use tower_http::cors::{Any, CorsLayer};
+use tower_http::services::ServeDir;
 use tower_http::trace::TraceLayer;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+mod auth;
+mod config;
+mod error;
+mod handlers;
+mod ids;
+mod media;
+mod models;
+mod openapi;
+mod request_id;
+mod schema;
+
+use config::Config;
+use openapi::ApiDoc;
 
 #[derive(Clone)]
 struct AppState {
     db: SqlitePool,
+    sqids: sqids::Sqids,
+    config: Config,
 }
 
 #[tokio::main]
@@ -28,11 +48,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load environment variables (non-fatal if missing)
     dotenvy::dotenv().ok();
 
-    // Get database URL with fallback
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "sqlite:///app/data/blog.db".to_string());
+    // Fails fast if a required secret (e.g. JWT_SECRET) is absent
+    let config = Config::load();
 
-    tracing::info!("Database URL: {}", database_url);
+    tracing::info!("Database URL: {}", config.database_url);
 
    // Around line 30, replace directory creation with:
 
@@ -70,9 +89,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // CRITICAL: Use connection pool with retry logic
     let db = SqlitePoolOptions::new()
-        .max_connections(5)
+        .max_connections(config.max_connections)
         .acquire_timeout(Duration::from_secs(3))
-        .connect(&database_url)
+        .connect(&config.database_url)
         .await
         .map_err(|e| {
             tracing::error!("Failed to connect to database: {}", e);
@@ -92,14 +111,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     tracing::info!("Migrations applied successfully");
 
-    let state = AppState { db };
+    let sqids = ids::build_sqids();
+
+    // CORS configuration - origins come from Config rather than being hardcoded
+    let cors_origins: Vec<HeaderValue> = config
+        .cors_origins
+        .iter()
+        .map(|origin| origin.parse().expect("invalid CORS origin in config"))
+        .collect();
+
+    let addr = config.bind_address();
+
+    let state = Arc::new(AppState { db, sqids, config });
 
-    // CORS configuration - adjust origins for your needs
     let cors = CorsLayer::new()
-        .allow_origin([
-            "http://localhost:3000".parse::<HeaderValue>().unwrap(),
-            "http://frontend:3000".parse::<HeaderValue>().unwrap(),
-        ])
+        .allow_origin(cors_origins)
         .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
         .allow_headers(Any)
         .allow_credentials(true);
@@ -108,17 +134,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let app = Router::new()
         .route("/", get(root))
         .route("/health", get(health_check))
-        .route("/api/posts", get(list_posts).post(create_post))
-        .route("/api/posts/:id", get(get_post))
+        .route("/api/register", post(auth::register))
+        .route("/api/login", post(auth::login))
+        .route(
+            "/api/posts",
+            get(handlers::list_posts).post(handlers::create_post),
+        )
+        .route(
+            "/api/posts/:sqid",
+            get(handlers::get_post)
+                .put(handlers::update_post)
+                .delete(handlers::delete_post),
+        )
+        .route(
+            "/api/posts/:sqid/image",
+            post(media::upload_image).layer(DefaultBodyLimit::max(media::UPLOAD_BODY_LIMIT)),
+        )
+        .nest_service("/uploads", ServeDir::new("/app/data/uploads"))
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         .layer(cors)
         .layer(TraceLayer::new_for_http())
+        .layer(middleware::from_fn(request_id::assign_request_id))
         .with_state(state);
 
     // CRITICAL: Bind to 0.0.0.0, not 127.0.0.1 for Docker!
-    let port = std::env::var("PORT")
-        .unwrap_or_else(|_| "3001".to_string());
-    let addr = format!("0.0.0.0:{}", port);
-
     tracing::info!("Listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(&addr)
@@ -140,87 +179,3 @@ async fn root() -> &'static str {
 async fn health_check() -> StatusCode {
     StatusCode::OK
 }
-
-#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
-struct Post {
-    id: i64,
-    title: String,
-    content: String,
-    author: String,
-    created_at: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct CreatePost {
-    title: String,
-    content: String,
-    author: String,
-}
-
-async fn list_posts(
-    State(state): State<AppState>,
-) -> Result<Json<Vec<Post>>, (StatusCode, String)> {
-    let posts = sqlx::query_as::<_, Post>(
-        "SELECT id, title, content, author, created_at FROM posts ORDER BY created_at DESC"
-    )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
-    })?;
-
-    Ok(Json(posts))
-}
-
-async fn create_post(
-    State(state): State<AppState>,
-    Json(payload): Json<CreatePost>,
-) -> Result<(StatusCode, Json<Post>), (StatusCode, String)> {
-    let result = sqlx::query(
-        "INSERT INTO posts (title, content, author) VALUES (?1, ?2, ?3)"
-    )
-    .bind(&payload.title)
-    .bind(&payload.content)
-    .bind(&payload.author)
-    .execute(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
-    })?;
-
-    let id = result.last_insert_rowid();
-
-    let post = sqlx::query_as::<_, Post>(
-        "SELECT id, title, content, author, created_at FROM posts WHERE id = ?1"
-    )
-    .bind(id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
-    })?;
-
-    Ok((StatusCode::CREATED, Json(post)))
-}
-
-async fn get_post(
-    State(state): State<AppState>,
-    Path(id): Path<i64>,
-) -> Result<Json<Post>, (StatusCode, String)> {
-    let post = sqlx::query_as::<_, Post>(
-        "SELECT id, title, content, author, created_at FROM posts WHERE id = ?1"
-    )
-    .bind(id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
-    })?
-    .ok_or_else(|| (StatusCode::NOT_FOUND, "Post not found".to_string()))?;
-
-    Ok(Json(post))
-}
@@ -0,0 +1,118 @@
+use axum::extract::{Multipart, Path, State};
+use axum::http::StatusCode;
+use image::{imageops::FilterType, GenericImageView};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::handlers::require_owner;
+use crate::ids;
+use crate::models::Post;
+use crate::schema::ApiResponse;
+
+type AppState = Arc<crate::AppState>;
+
+const MAX_UPLOAD_BYTES: usize = 8 * 1024 * 1024;
+/// Body limit applied to the upload route in `main.rs`. Axum's default
+/// extractor cap is 2 MiB, well under `MAX_UPLOAD_BYTES`; this needs to be
+/// comfortably above `MAX_UPLOAD_BYTES` (not equal to it) so oversized
+/// uploads still reach the `ValidationError` check below instead of being
+/// rejected by the body-limit layer first.
+pub(crate) const UPLOAD_BODY_LIMIT: usize = MAX_UPLOAD_BYTES + 64 * 1024;
+const THUMB_MAX_DIMENSION: u32 = 1200;
+const UPLOAD_DIR: &str = "uploads";
+const ALLOWED_CONTENT_TYPES: &[&str] = &["image/jpeg", "image/png", "image/webp"];
+
+/// Accepts a multipart image upload, stores the original plus a bounded-size
+/// thumbnail under the data directory, and records both paths on the post.
+/// Only the post's author may replace its cover image.
+pub async fn upload_image(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(sqid): Path<String>,
+    mut multipart: Multipart,
+) -> Result<ApiResponse<Post>, AppError> {
+    let id = ids::decode(&state.sqids, &sqid).ok_or(AppError::NotFound)?;
+    require_owner(&state.db, id, &user.username).await?;
+
+    let field = multipart
+        .next_field()
+        .await
+        .map_err(|_| AppError::ValidationError("Invalid multipart payload".to_string()))?
+        .ok_or_else(|| AppError::ValidationError("Expected an image field".to_string()))?;
+
+    let content_type = field.content_type().unwrap_or("").to_string();
+    if !ALLOWED_CONTENT_TYPES.contains(&content_type.as_str()) {
+        return Err(AppError::ValidationError(format!(
+            "Unsupported image type: {content_type}"
+        )));
+    }
+
+    let extension = mime_guess::get_mime_extensions_str(&content_type)
+        .and_then(|exts| exts.first())
+        .ok_or_else(|| AppError::ValidationError(format!("Unsupported image type: {content_type}")))?;
+
+    let bytes = field
+        .bytes()
+        .await
+        .map_err(|_| AppError::ValidationError("Failed to read upload".to_string()))?;
+
+    if bytes.len() > MAX_UPLOAD_BYTES {
+        return Err(AppError::ValidationError(format!(
+            "Image exceeds the maximum upload size of {MAX_UPLOAD_BYTES} bytes"
+        )));
+    }
+
+    let original = image::load_from_memory(&bytes)
+        .map_err(|_| AppError::ValidationError("Could not decode image".to_string()))?;
+
+    // `resize` scales up to fill the bounding box, so skip it when the
+    // source already fits — otherwise a small cover image gets a "thumbnail"
+    // larger than itself.
+    let (width, height) = original.dimensions();
+    let thumbnail = if width <= THUMB_MAX_DIMENSION && height <= THUMB_MAX_DIMENSION {
+        original.clone()
+    } else {
+        original.resize(THUMB_MAX_DIMENSION, THUMB_MAX_DIMENSION, FilterType::Lanczos3)
+    };
+
+    let upload_dir = std::path::Path::new("/app/data").join(UPLOAD_DIR);
+    std::fs::create_dir_all(&upload_dir).map_err(|_| AppError::InternalError)?;
+
+    let stem = Uuid::new_v4();
+    let image_name = format!("{stem}.{extension}");
+    let thumb_name = format!("{stem}_thumb.{extension}");
+
+    original
+        .save(upload_dir.join(&image_name))
+        .map_err(|_| AppError::InternalError)?;
+    thumbnail
+        .save(upload_dir.join(&thumb_name))
+        .map_err(|_| AppError::InternalError)?;
+
+    let image_path = format!("{UPLOAD_DIR}/{image_name}");
+    let thumb_path = format!("{UPLOAD_DIR}/{thumb_name}");
+
+    let mut post = sqlx::query_as::<_, Post>(
+        r#"
+        UPDATE posts
+        SET image_path = ?, thumb_path = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        RETURNING id, title, content, author, status, image_path, thumb_path, created_at, updated_at
+        "#
+    )
+    .bind(&image_path)
+    .bind(&thumb_path)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => AppError::NotFound,
+        _ => AppError::DatabaseError(e),
+    })?;
+
+    post.sqid = sqid;
+
+    Ok(ApiResponse::new(post, StatusCode::OK))
+}
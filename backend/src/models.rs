@@ -1,22 +1,30 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct Post {
+    #[serde(skip_serializing)]
     pub id: i64,
+    /// Opaque, non-sequential public id, derived from `id` via sqids.
+    #[sqlx(default)]
+    pub sqid: String,
     pub title: String,
     pub content: String,
     pub author: String,
     pub status: String,
+    /// Relative path (under the data directory) to the full-size cover image.
+    pub image_path: Option<String>,
+    /// Relative path (under the data directory) to the resized thumbnail.
+    pub thumb_path: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreatePost {
     pub title: String,
     pub content: String,
-    pub author: String,
     #[serde(default = "default_status")]
     pub status: String,
 }
@@ -25,10 +33,30 @@ fn default_status() -> String {
     "draft".to_string()
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdatePost {
     pub title: Option<String>,
     pub content: Option<String>,
-    pub author: Option<String>,
     pub status: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct User {
+    pub id: i64,
+    pub username: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterUser {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginUser {
+    pub username: String,
+    pub password: String,
+}
@@ -0,0 +1,32 @@
+use utoipa::OpenApi;
+
+use crate::error::ErrorBody;
+use crate::models::{CreatePost, Post, UpdatePost};
+use crate::schema::{
+    ApiResponsePaginatedResponsePost, ApiResponsePost, PaginatedResponsePost, PaginationParams,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::handlers::list_posts,
+        crate::handlers::get_post,
+        crate::handlers::create_post,
+        crate::handlers::update_post,
+        crate::handlers::delete_post,
+    ),
+    components(schemas(
+        Post,
+        CreatePost,
+        UpdatePost,
+        PaginationParams,
+        PaginatedResponsePost,
+        ApiResponsePost,
+        ApiResponsePaginatedResponsePost,
+        ErrorBody,
+    )),
+    tags(
+        (name = "posts", description = "Blog post CRUD endpoints"),
+    ),
+)]
+pub struct ApiDoc;
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{FromRequestParts, Request},
+    http::{request::Parts, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// The id assigned to the current request by [`assign_request_id`], also
+/// stored in the request's extensions the same way
+/// [`crate::auth::AuthUser`] is, for handlers that want it via an extractor
+/// rather than [`current_request_id`].
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+impl<S> FromRequestParts<S> for RequestId
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<RequestId>()
+            .cloned()
+            .unwrap_or_else(|| RequestId(String::new())))
+    }
+}
+
+/// Assigns a UUID to every request, stores it in the request's extensions,
+/// makes it available to the rest of the request's call graph via a
+/// task-local (so `AppError::into_response` and `ApiResponse::new` can read
+/// it without threading it through every function signature or rewriting
+/// response bodies after the fact), opens a tracing span carrying it, and
+/// echoes it back as `x-request-id`.
+pub async fn assign_request_id(mut req: Request, next: Next) -> Response {
+    let request_id = Uuid::new_v4().to_string();
+    req.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let span = tracing::info_span!("request", request_id = %request_id);
+    let mut response = CURRENT_REQUEST_ID
+        .scope(request_id.clone(), next.run(req).instrument(span))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
+
+/// Reads the current request's id. Returns an empty string outside of a
+/// request handled by [`assign_request_id`] (e.g. in unit tests).
+pub fn current_request_id() -> String {
+    CURRENT_REQUEST_ID.try_with(|id| id.clone()).unwrap_or_default()
+}
@@ -0,0 +1,90 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::Post;
+use crate::request_id::current_request_id;
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_per_page() -> i64 {
+    20
+}
+
+fn default_sort() -> String {
+    "created_at".to_string()
+}
+
+fn default_order() -> String {
+    "desc".to_string()
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PaginationParams {
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_per_page")]
+    pub per_page: i64,
+    /// Free-text search term, matched against title and content via FTS5.
+    #[serde(default)]
+    pub q: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Column to sort by, or `rank` to order by FTS5 relevance (only valid
+    /// alongside `q`). Validated against an allowlist before use.
+    #[serde(default = "default_sort")]
+    pub sort: String,
+    /// Sort direction: "asc" or "desc". Validated against an allowlist before
+    /// use; ignored when `sort` is `rank`, which is always most-relevant-first.
+    #[serde(default = "default_order")]
+    pub order: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[aliases(PaginatedResponsePost = PaginatedResponse<Post>)]
+pub struct PaginatedResponse<T> {
+    pub data: Vec<T>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+}
+
+/// Uniform success envelope. Mirrors the `{ error, status }` shape of
+/// [`crate::error::AppError`] so clients get a consistent response shape for
+/// both success and error, with the same `request_id` on both sides for
+/// correlating a failing call with its tracing span.
+#[derive(Debug, Serialize, ToSchema)]
+#[aliases(
+    ApiResponsePost = ApiResponse<Post>,
+    ApiResponsePaginatedResponsePost = ApiResponse<PaginatedResponsePost>,
+)]
+pub struct ApiResponse<T> {
+    pub data: T,
+    pub status: u16,
+    pub request_id: String,
+}
+
+impl<T> ApiResponse<T> {
+    pub fn new(data: T, status: StatusCode) -> Self {
+        ApiResponse {
+            data,
+            status: status.as_u16(),
+            request_id: current_request_id(),
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for ApiResponse<T> {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK);
+        (status, Json(self)).into_response()
+    }
+}
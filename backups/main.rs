@@ -1,4 +1,5 @@
 use axum::{
+    http::HeaderValue,
     routing::{get, post, put, delete},
     Router,
 };
@@ -22,6 +23,50 @@ struct AppState {
     db: SqlitePool,
 }
 
+/// Typed configuration, read once at startup so the rest of the binary never
+/// touches `std::env` directly. Mirrors `backend/src/config.rs`.
+struct Config {
+    database_url: String,
+    port: u16,
+    max_connections: u32,
+    cors_origins: Vec<String>,
+}
+
+impl Config {
+    fn load() -> Self {
+        let database_url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| "sqlite:///app/data/database.db".to_string());
+
+        let port = std::env::var("PORT")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(8000);
+
+        let max_connections = std::env::var("MAX_CONNECTIONS")
+            .ok()
+            .and_then(|m| m.parse().ok())
+            .unwrap_or(10);
+
+        let cors_origins = std::env::var("CORS_ORIGINS")
+            .ok()
+            .map(|origins| {
+                origins
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Config {
+            database_url,
+            port,
+            max_connections,
+            cors_origins,
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing
@@ -36,15 +81,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load environment variables
     dotenvy::dotenv().ok();
 
-    // Create database connection pool
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "sqlite:///app/data/database.db".to_string());
+    let config = Config::load();
 
-    tracing::info!("Connecting to database: {}", database_url);
+    // Create database connection pool
+    tracing::info!("Connecting to database: {}", config.database_url);
 
     let pool = SqlitePoolOptions::new()
-        .max_connections(10)
-        .connect(&database_url)
+        .max_connections(config.max_connections)
+        .connect(&config.database_url)
         .await?;
 
     // Run migrations
@@ -53,27 +97,40 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let state = Arc::new(AppState { db: pool });
 
+    // Empty CORS_ORIGINS keeps the previous wide-open behavior for local dev
+    let cors = if config.cors_origins.is_empty() {
+        CorsLayer::new()
+            .allow_origin(Any)
+            .allow_methods(Any)
+            .allow_headers(Any)
+    } else {
+        let origins: Vec<HeaderValue> = config
+            .cors_origins
+            .iter()
+            .map(|origin| origin.parse().expect("invalid CORS origin in config"))
+            .collect();
+        CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods(Any)
+            .allow_headers(Any)
+    };
+
     // Build router
     let app = Router::new()
         .route("/health", get(health_check))
         .route("/posts", get(handlers::list_posts).post(handlers::create_post))
-        .route("/posts/:id", 
+        .route("/posts/:id",
             get(handlers::get_post)
             .put(handlers::update_post)
             .delete(handlers::delete_post)
         )
         .layer(TraceLayer::new_for_http())
-        .layer(
-            CorsLayer::new()
-                .allow_origin(Any)
-                .allow_methods(Any)
-                .allow_headers(Any)
-        )
+        .layer(cors)
         .with_state(state);
 
     // Start server
-    let addr = "0.0.0.0:8000";
-    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let addr = format!("0.0.0.0:{}", config.port);
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
 
     tracing::info!("Server listening on {}", addr);
 